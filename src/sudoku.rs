@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use rand::prelude::*;
+
 /// Represents a sudoku grid. The grid is represented
 /// using a [u8; 81] array, and the blank cells are represented with `0`s.
 #[derive(Debug, Clone, Copy)]
@@ -32,8 +34,7 @@ impl Sudoku {
             .chunks_exact(9)
             .nth(row_index)
             .unwrap()
-            .iter()
-            .any(|n| *n == number)
+            .contains(&number)
     }
 
     /// Checks if the given number can be put in the given column.
@@ -56,8 +57,7 @@ impl Sudoku {
                     .chunks_exact(3)
                     .nth(x / 3)
                     .unwrap()
-                    .iter()
-                    .any(|n| *n == number)
+                    .contains(&number)
             })
     }
 
@@ -71,33 +71,125 @@ impl Sudoku {
         (curr_idx % 9, curr_idx / 9)
     }
 
-    /// Recursively solves the sudoku through backtracking,
-    /// starting from the given index.
-    fn solve_internals(&mut self, curr_idx: usize) {
+    /// Fills in every empty cell that has a single remaining candidate,
+    /// looping until none are left, and returns the indices it filled in.
+    fn propagate_singles(
+        &mut self,
+        rows: &mut [u16; 9],
+        cols: &mut [u16; 9],
+        boxes: &mut [u16; 9],
+    ) -> Vec<usize> {
+        let mut filled = Vec::new();
+
+        loop {
+            let mut progress = false;
+
+            for idx in 0..81 {
+                if self.grid[idx] != 0 {
+                    continue;
+                }
+
+                let (x, y) = Self::get_coordinates(idx);
+                let b = (y / 3) * 3 + x / 3;
+                let candidates = !(rows[y] | cols[x] | boxes[b]) & 0x1FF;
+
+                if candidates.count_ones() == 1 {
+                    let bit = candidates.trailing_zeros();
+                    self.grid[idx] = bit as u8 + 1;
+                    rows[y] |= 1 << bit;
+                    cols[x] |= 1 << bit;
+                    boxes[b] |= 1 << bit;
+                    filled.push(idx);
+                    progress = true;
+                }
+            }
+
+            if !progress {
+                break;
+            }
+        }
+
+        filled
+    }
+
+    /// Recursively solves the sudoku through backtracking, starting from the
+    /// given index, using bitmask candidates and naked-single propagation.
+    fn solve_internals(
+        &mut self,
+        curr_idx: usize,
+        rows: &mut [u16; 9],
+        cols: &mut [u16; 9],
+        boxes: &mut [u16; 9],
+    ) {
         if curr_idx == 81 {
             self.is_solved = true;
             return;
         }
 
+        let filled = self.propagate_singles(rows, cols, boxes);
+
         if self.grid[curr_idx] != 0 {
-            self.solve_internals(curr_idx + 1);
+            self.solve_internals(curr_idx + 1, rows, cols, boxes);
         } else {
-            for n in 1..=9 {
-                if self.is_valid(n, Self::get_coordinates(curr_idx)) {
-                    self.grid[curr_idx] = n;
+            let (x, y) = Self::get_coordinates(curr_idx);
+            let b = (y / 3) * 3 + x / 3;
+            let mut candidates = !(rows[y] | cols[x] | boxes[b]) & 0x1FF;
 
-                    self.solve_internals(curr_idx + 1);
+            while candidates != 0 && !self.is_solved {
+                let bit = candidates.trailing_zeros();
+                candidates &= candidates - 1;
 
-                    if self.is_solved {
-                        return;
-                    }
+                self.grid[curr_idx] = bit as u8 + 1;
+                rows[y] |= 1 << bit;
+                cols[x] |= 1 << bit;
+                boxes[b] |= 1 << bit;
+
+                self.solve_internals(curr_idx + 1, rows, cols, boxes);
+
+                if !self.is_solved {
+                    rows[y] &= !(1 << bit);
+                    cols[x] &= !(1 << bit);
+                    boxes[b] &= !(1 << bit);
+                    self.grid[curr_idx] = 0;
                 }
             }
+        }
 
-            self.grid[curr_idx] = 0;
+        if !self.is_solved {
+            for idx in filled {
+                let (x, y) = Self::get_coordinates(idx);
+                let b = (y / 3) * 3 + x / 3;
+                let bit = (self.grid[idx] - 1) as u32;
+                rows[y] &= !(1 << bit);
+                cols[x] &= !(1 << bit);
+                boxes[b] &= !(1 << bit);
+                self.grid[idx] = 0;
+            }
         }
     }
 
+    /// Computes the row/column/box candidate masks implied by the grid's
+    /// current givens, for use by [`Sudoku::solve_internals`] and
+    /// [`Sudoku::count_solutions_internals`].
+    fn initial_masks(&self) -> ([u16; 9], [u16; 9], [u16; 9]) {
+        let mut rows = [0u16; 9];
+        let mut cols = [0u16; 9];
+        let mut boxes = [0u16; 9];
+
+        for idx in 0..81 {
+            if self.grid[idx] != 0 {
+                let (x, y) = Self::get_coordinates(idx);
+                let b = (y / 3) * 3 + x / 3;
+                let bit = (self.grid[idx] - 1) as u32;
+                rows[y] |= 1 << bit;
+                cols[x] |= 1 << bit;
+                boxes[b] |= 1 << bit;
+            }
+        }
+
+        (rows, cols, boxes)
+    }
+
     /// Solves the sudoku by using recursion and backtracking.
     ///
     /// # Example
@@ -105,11 +197,397 @@ impl Sudoku {
     /// ```
     /// # use sudoku::sudoku::Sudoku;
     /// let mut sudoku = Sudoku::default(); // empty grid
-    /// 
+    ///
     /// sudoku.solve() // the sudoku is now solved!
     /// ```
     pub fn solve(&mut self) {
-        self.solve_internals(0);
+        let (mut rows, mut cols, mut boxes) = self.initial_masks();
+
+        self.solve_internals(0, &mut rows, &mut cols, &mut boxes);
+    }
+
+    /// Recursively counts completions reachable from `curr_idx` by trying
+    /// every candidate at each empty cell, stopping as soon as `*count`
+    /// reaches `limit` instead of returning after the first completion like
+    /// [`Sudoku::solve_internals`] does.
+    fn count_solutions_internals(
+        &mut self,
+        curr_idx: usize,
+        rows: &mut [u16; 9],
+        cols: &mut [u16; 9],
+        boxes: &mut [u16; 9],
+        count: &mut usize,
+        limit: usize,
+    ) {
+        if *count >= limit {
+            return;
+        }
+
+        if curr_idx == 81 {
+            *count += 1;
+            return;
+        }
+
+        if self.grid[curr_idx] != 0 {
+            self.count_solutions_internals(curr_idx + 1, rows, cols, boxes, count, limit);
+        } else {
+            let (x, y) = Self::get_coordinates(curr_idx);
+            let b = (y / 3) * 3 + x / 3;
+            let mut candidates = !(rows[y] | cols[x] | boxes[b]) & 0x1FF;
+
+            while candidates != 0 && *count < limit {
+                let bit = candidates.trailing_zeros();
+                candidates &= candidates - 1;
+
+                self.grid[curr_idx] = bit as u8 + 1;
+                rows[y] |= 1 << bit;
+                cols[x] |= 1 << bit;
+                boxes[b] |= 1 << bit;
+
+                self.count_solutions_internals(curr_idx + 1, rows, cols, boxes, count, limit);
+
+                rows[y] &= !(1 << bit);
+                cols[x] &= !(1 << bit);
+                boxes[b] &= !(1 << bit);
+                self.grid[curr_idx] = 0;
+            }
+        }
+    }
+
+    /// Counts how many distinct completions this grid has, stopping as soon
+    /// as `limit` is reached so callers checking for uniqueness don't pay
+    /// for an exhaustive search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let sudoku = Sudoku::default(); // empty grid
+    ///
+    /// assert_eq!(sudoku.count_solutions(1), 1);
+    /// ```
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        let mut sudoku = *self;
+        let (mut rows, mut cols, mut boxes) = sudoku.initial_masks();
+        let mut count = 0;
+
+        sudoku.count_solutions_internals(0, &mut rows, &mut cols, &mut boxes, &mut count, limit);
+
+        count
+    }
+
+    /// Returns whether this grid has exactly one solution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let sudoku = Sudoku::default(); // empty grid
+    ///
+    /// assert!(!sudoku.is_unique()); // an empty grid has many solutions
+    /// ```
+    pub fn is_unique(&self) -> bool {
+        self.count_solutions(2) == 1
+    }
+
+    /// Checks that no row, column or 3×3 box contains the same nonzero
+    /// digit twice. A grid can fail this even though every individual cell
+    /// is in range `0..=9`; the `TryFrom` impls run this check after the
+    /// range check and return [`SudokuError::Contradiction`] when it fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let sudoku = Sudoku::default(); // blank grid
+    ///
+    /// assert!(sudoku.is_consistent());
+    /// ```
+    pub fn is_consistent(&self) -> bool {
+        let mut rows = [0u16; 9];
+        let mut cols = [0u16; 9];
+        let mut boxes = [0u16; 9];
+
+        for idx in 0..81 {
+            let n = self.grid[idx];
+
+            if n == 0 {
+                continue;
+            }
+
+            let (x, y) = Self::get_coordinates(idx);
+            let b = (y / 3) * 3 + x / 3;
+            let bit = 1 << (n - 1);
+
+            if rows[y] & bit != 0 || cols[x] & bit != 0 || boxes[b] & bit != 0 {
+                return false;
+            }
+
+            rows[y] |= bit;
+            cols[x] |= bit;
+            boxes[b] |= bit;
+        }
+
+        true
+    }
+
+    /// Recursively fills the grid into a complete solution, trying
+    /// candidates at each empty cell in a randomized order.
+    fn fill_randomly<R: Rng + ?Sized>(
+        &mut self,
+        curr_idx: usize,
+        rows: &mut [u16; 9],
+        cols: &mut [u16; 9],
+        boxes: &mut [u16; 9],
+        rng: &mut R,
+    ) -> bool {
+        if curr_idx == 81 {
+            return true;
+        }
+
+        if self.grid[curr_idx] != 0 {
+            return self.fill_randomly(curr_idx + 1, rows, cols, boxes, rng);
+        }
+
+        let (x, y) = Self::get_coordinates(curr_idx);
+        let b = (y / 3) * 3 + x / 3;
+        let candidates = !(rows[y] | cols[x] | boxes[b]) & 0x1FF;
+
+        let mut digits: Vec<u8> = (1..=9).filter(|d| candidates & (1 << (d - 1)) != 0).collect();
+        digits.shuffle(rng);
+
+        for n in digits {
+            let bit = (n - 1) as u32;
+
+            self.grid[curr_idx] = n;
+            rows[y] |= 1 << bit;
+            cols[x] |= 1 << bit;
+            boxes[b] |= 1 << bit;
+
+            if self.fill_randomly(curr_idx + 1, rows, cols, boxes, rng) {
+                return true;
+            }
+
+            rows[y] &= !(1 << bit);
+            cols[x] &= !(1 << bit);
+            boxes[b] &= !(1 << bit);
+            self.grid[curr_idx] = 0;
+        }
+
+        false
+    }
+
+    /// Generates a playable puzzle with a guaranteed unique solution, drawing
+    /// randomness from `rng` for reproducibility. Clues are removed until
+    /// `clues` remain or until no further clue can be removed without losing
+    /// uniqueness, so the result can have more than `clues` givens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let mut rng = rand::thread_rng();
+    /// let sudoku = Sudoku::generate_with_rng(30, &mut rng);
+    ///
+    /// assert!(sudoku.is_unique());
+    /// ```
+    pub fn generate_with_rng<R: Rng + ?Sized>(clues: usize, rng: &mut R) -> Self {
+        let mut sudoku = Self::default();
+        let (mut rows, mut cols, mut boxes) = sudoku.initial_masks();
+
+        sudoku.fill_randomly(0, &mut rows, &mut cols, &mut boxes, rng);
+
+        let mut remaining = 81;
+
+        loop {
+            if remaining <= clues {
+                break;
+            }
+
+            let mut indices: Vec<usize> = (0..81).filter(|&idx| sudoku.grid[idx] != 0).collect();
+            indices.shuffle(rng);
+
+            let mut progress = false;
+
+            for idx in indices {
+                if remaining <= clues {
+                    break;
+                }
+
+                let digit = sudoku.grid[idx];
+                sudoku.grid[idx] = 0;
+
+                if sudoku.is_unique() {
+                    remaining -= 1;
+                    progress = true;
+                } else {
+                    sudoku.grid[idx] = digit;
+                }
+            }
+
+            if !progress {
+                break;
+            }
+        }
+
+        sudoku
+    }
+
+    /// Generates a playable puzzle with a guaranteed unique solution, removing
+    /// clues as [`Sudoku::generate_with_rng`] does, using the thread-local RNG.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let sudoku = Sudoku::generate(30);
+    ///
+    /// assert!(sudoku.is_unique());
+    /// ```
+    pub fn generate(clues: usize) -> Self {
+        Self::generate_with_rng(clues, &mut rand::thread_rng())
+    }
+
+    /// Maps `(x, y)` to the spreadsheet-like coordinates used in
+    /// [`SolvingStep`]'s output: column letter `A`-`I`, row digit `1`-`9`.
+    fn cell_name((x, y): (usize, usize)) -> String {
+        let column = (b'A' + x as u8) as char;
+
+        format!("{}{}", column, y + 1)
+    }
+
+    /// Looks for an empty cell with exactly one remaining candidate.
+    fn find_naked_single(
+        &self,
+        rows: &[u16; 9],
+        cols: &[u16; 9],
+        boxes: &[u16; 9],
+    ) -> Option<SolvingStep> {
+        for idx in 0..81 {
+            if self.grid[idx] != 0 {
+                continue;
+            }
+
+            let (x, y) = Self::get_coordinates(idx);
+            let b = (y / 3) * 3 + x / 3;
+            let candidates = !(rows[y] | cols[x] | boxes[b]) & 0x1FF;
+
+            if candidates.count_ones() == 1 {
+                return Some(SolvingStep {
+                    cell: (x, y),
+                    digit: candidates.trailing_zeros() as u8 + 1,
+                    technique: Technique::NakedSingle,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Looks for a digit that fits in only one empty cell of some row,
+    /// column or box.
+    fn find_hidden_single(
+        &self,
+        rows: &[u16; 9],
+        cols: &[u16; 9],
+        boxes: &[u16; 9],
+    ) -> Option<SolvingStep> {
+        let houses = (0..9)
+            .map(House::Row)
+            .chain((0..9).map(House::Column))
+            .chain((0..9).map(House::Box));
+
+        for house in houses {
+            for digit in 1..=9u8 {
+                let bit = 1 << (digit - 1);
+                let mut candidate = None;
+                let mut count = 0;
+
+                for idx in house.cells() {
+                    if self.grid[idx] != 0 {
+                        continue;
+                    }
+
+                    let (x, y) = Self::get_coordinates(idx);
+                    let b = (y / 3) * 3 + x / 3;
+
+                    if rows[y] & bit == 0 && cols[x] & bit == 0 && boxes[b] & bit == 0 {
+                        count += 1;
+                        candidate = Some((x, y));
+                    }
+                }
+
+                if count == 1 {
+                    return Some(SolvingStep {
+                        cell: candidate.unwrap(),
+                        digit,
+                        technique: Technique::HiddenSingle(house),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Applies a previously found step to the grid and the candidate masks.
+    fn apply_step(
+        &mut self,
+        step: SolvingStep,
+        rows: &mut [u16; 9],
+        cols: &mut [u16; 9],
+        boxes: &mut [u16; 9],
+    ) {
+        let (x, y) = step.cell;
+        let b = (y / 3) * 3 + x / 3;
+        let bit = 1 << (step.digit - 1);
+
+        self.grid[y * 9 + x] = step.digit;
+        rows[y] |= bit;
+        cols[x] |= bit;
+        boxes[b] |= bit;
+    }
+
+    /// Solves the sudoku while recording the human-style reasoning it uses
+    /// — naked singles and hidden singles, named with spreadsheet-style
+    /// cell coordinates via [`Sudoku::cell_name`] — and falls back to
+    /// [`Sudoku::solve`] once no logical step applies. Returns the steps in
+    /// the order they were applied, which is useful for hints or teaching
+    /// rather than just a black-box solved grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let mut sudoku = Sudoku::default(); // empty grid
+    ///
+    /// let steps = sudoku.solve_logged(); // the sudoku is now solved!
+    /// ```
+    pub fn solve_logged(&mut self) -> Vec<SolvingStep> {
+        let mut steps = Vec::new();
+        let (mut rows, mut cols, mut boxes) = self.initial_masks();
+
+        loop {
+            let step = self
+                .find_naked_single(&rows, &cols, &boxes)
+                .or_else(|| self.find_hidden_single(&rows, &cols, &boxes));
+
+            match step {
+                Some(step) => {
+                    self.apply_step(step, &mut rows, &mut cols, &mut boxes);
+                    steps.push(step);
+                }
+                None => break,
+            }
+        }
+
+        if self.grid.contains(&0) {
+            self.solve();
+        } else {
+            self.is_solved = true;
+        }
+
+        steps
     }
 
     /// Returns the current state of the sudoku.
@@ -119,12 +597,265 @@ impl Sudoku {
     /// ```
     /// # use sudoku::sudoku::Sudoku;
     /// let sudoku = Sudoku::default(); // blank grid
-    /// 
+    ///
     /// assert_eq!(sudoku.grid(), [0; 81]);
     /// ```
     pub fn grid(&self) -> [u8; 81] {
         self.grid
     }
+
+    /// Returns the four exact-cover column indices (cell, row, column and
+    /// box constraints) lit up by placing `d` at `(x, y)`, for use by
+    /// [`Sudoku::build_dlx`].
+    fn dlx_columns((x, y): (usize, usize), idx: usize, d: u8) -> [usize; 4] {
+        let b = (y / 3) * 3 + x / 3;
+        let di = (d - 1) as usize;
+
+        [
+            1 + idx,
+            1 + 81 + y * 9 + di,
+            1 + 162 + x * 9 + di,
+            1 + 243 + b * 9 + di,
+        ]
+    }
+
+    /// Builds the Dancing Links matrix for this grid's 324 constraint
+    /// columns and candidate `(cell, digit)` rows.
+    fn build_dlx(&self) -> Dlx {
+        const COLUMNS: usize = 324;
+
+        let mut nodes = vec![
+            DlxNode {
+                left: 0,
+                right: 0,
+                up: 0,
+                down: 0,
+                column: 0,
+                row_id: 0,
+            };
+            COLUMNS + 1
+        ];
+
+        nodes[0].left = COLUMNS;
+        nodes[0].right = 1;
+
+        for (c, node) in nodes.iter_mut().enumerate().take(COLUMNS + 1).skip(1) {
+            *node = DlxNode {
+                left: c - 1,
+                right: if c == COLUMNS { 0 } else { c + 1 },
+                up: c,
+                down: c,
+                column: c,
+                row_id: 0,
+            };
+        }
+
+        let size = vec![0usize; COLUMNS + 1];
+        let mut dlx = Dlx {
+            nodes,
+            size,
+            rows: Vec::new(),
+        };
+
+        for idx in 0..81 {
+            let coordinates = Self::get_coordinates(idx);
+            let digits: Vec<u8> = if self.grid[idx] != 0 {
+                vec![self.grid[idx]]
+            } else {
+                (1..=9).filter(|d| self.is_valid(*d, coordinates)).collect()
+            };
+
+            for d in digits {
+                dlx.add_row(Self::dlx_columns(coordinates, idx, d), idx, d);
+            }
+        }
+
+        dlx
+    }
+
+    /// Solves the sudoku with Dancing Links (Algorithm X) over the exact-cover
+    /// encoding built by [`Sudoku::build_dlx`], recursively choosing the
+    /// constraint column with the fewest candidates, covering it, and
+    /// covering/uncovering the columns of each candidate row as it
+    /// backtracks. On hard puzzles this is typically orders of magnitude
+    /// faster than [`Sudoku::solve`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sudoku::sudoku::Sudoku;
+    /// let mut sudoku = Sudoku::default(); // empty grid
+    ///
+    /// sudoku.solve_dlx() // the sudoku is now solved!
+    /// ```
+    pub fn solve_dlx(&mut self) {
+        let mut dlx = self.build_dlx();
+        let mut solution = Vec::new();
+
+        if dlx.search(&mut solution) {
+            for row_id in solution {
+                let (idx, d) = dlx.rows[row_id];
+                self.grid[idx] = d;
+            }
+
+            self.is_solved = true;
+        }
+    }
+}
+
+/// A node of the toroidal doubly-linked list used by [`Sudoku::solve_dlx`].
+///
+/// Nodes live in a flat arena and are linked by index rather than by
+/// pointer, which keeps the structure safe without needing `unsafe` or
+/// reference counting.
+#[derive(Debug, Clone, Copy)]
+struct DlxNode {
+    left: usize,
+    right: usize,
+    up: usize,
+    down: usize,
+    column: usize,
+    row_id: usize,
+}
+
+/// The Dancing Links matrix built from a sudoku grid, plus the mapping from
+/// each candidate row back to the `(cell, digit)` it represents.
+struct Dlx {
+    nodes: Vec<DlxNode>,
+    size: Vec<usize>,
+    rows: Vec<(usize, u8)>,
+}
+
+impl Dlx {
+    /// Appends a new candidate row spanning the given columns, linking it
+    /// into each column's vertical list and into its own horizontal ring.
+    fn add_row(&mut self, columns: [usize; 4], idx: usize, d: u8) {
+        let row_id = self.rows.len();
+        self.rows.push((idx, d));
+
+        let mut first: Option<usize> = None;
+
+        for column in columns {
+            let node_id = self.nodes.len();
+
+            let up = self.nodes[column].up;
+            self.nodes.push(DlxNode {
+                left: node_id,
+                right: node_id,
+                up,
+                down: column,
+                column,
+                row_id,
+            });
+
+            self.nodes[up].down = node_id;
+            self.nodes[column].up = node_id;
+            self.size[column] += 1;
+
+            if let Some(first) = first {
+                let prev = self.nodes[first].left;
+                self.nodes[node_id].left = prev;
+                self.nodes[node_id].right = first;
+                self.nodes[prev].right = node_id;
+                self.nodes[first].left = node_id;
+            } else {
+                first = Some(node_id);
+            }
+        }
+    }
+
+    /// Unlinks column `c` from the header row and removes every row that
+    /// has a node in it from the columns they also occupy.
+    fn cover(&mut self, c: usize) {
+        let l = self.nodes[c].left;
+        let r = self.nodes[c].right;
+        self.nodes[l].right = r;
+        self.nodes[r].left = l;
+
+        let mut i = self.nodes[c].down;
+        while i != c {
+            let mut j = self.nodes[i].right;
+            while j != i {
+                let u = self.nodes[j].up;
+                let d = self.nodes[j].down;
+                self.nodes[u].down = d;
+                self.nodes[d].up = u;
+                self.size[self.nodes[j].column] -= 1;
+                j = self.nodes[j].right;
+            }
+            i = self.nodes[i].down;
+        }
+    }
+
+    /// Reverses a prior [`Dlx::cover`] of column `c`.
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.nodes[c].up;
+        while i != c {
+            let mut j = self.nodes[i].left;
+            while j != i {
+                self.size[self.nodes[j].column] += 1;
+                let u = self.nodes[j].up;
+                let d = self.nodes[j].down;
+                self.nodes[u].down = j;
+                self.nodes[d].up = j;
+                j = self.nodes[j].left;
+            }
+            i = self.nodes[i].up;
+        }
+
+        let l = self.nodes[c].left;
+        let r = self.nodes[c].right;
+        self.nodes[l].right = c;
+        self.nodes[r].left = c;
+    }
+
+    /// Recursively searches for an exact cover, branching on the column
+    /// with the fewest remaining candidates.
+    fn search(&mut self, solution: &mut Vec<usize>) -> bool {
+        if self.nodes[0].right == 0 {
+            return true;
+        }
+
+        let mut c = self.nodes[0].right;
+        let mut best = c;
+        while c != 0 {
+            if self.size[c] < self.size[best] {
+                best = c;
+            }
+            c = self.nodes[c].right;
+        }
+        let c = best;
+
+        self.cover(c);
+
+        let mut r = self.nodes[c].down;
+        while r != c {
+            solution.push(self.nodes[r].row_id);
+
+            let mut j = self.nodes[r].right;
+            while j != r {
+                self.cover(self.nodes[j].column);
+                j = self.nodes[j].right;
+            }
+
+            if self.search(solution) {
+                return true;
+            }
+
+            solution.pop();
+
+            let mut j = self.nodes[r].left;
+            while j != r {
+                self.uncover(self.nodes[j].column);
+                j = self.nodes[j].left;
+            }
+
+            r = self.nodes[r].down;
+        }
+
+        self.uncover(c);
+        false
+    }
 }
 
 impl TryFrom<[u8; 81]> for Sudoku {
@@ -134,10 +865,16 @@ impl TryFrom<[u8; 81]> for Sudoku {
         if grid.iter().any(|n| *n > 9) {
             Err(SudokuError::InvalidCell)
         } else {
-            Ok(Self {
+            let sudoku = Self {
                 grid,
-                is_solved: false
-            })
+                is_solved: false,
+            };
+
+            if sudoku.is_consistent() {
+                Ok(sudoku)
+            } else {
+                Err(SudokuError::Contradiction)
+            }
         }
     }
 }
@@ -149,12 +886,57 @@ impl TryFrom<[[u8; 9]; 9]> for Sudoku {
         if input_grid.iter().any(|row| row.iter().any(|n| *n > 9)) {
             Err(SudokuError::InvalidCell)
         } else {
-            Ok(Self {
+            let sudoku = Self {
                 grid: Self::flatten(input_grid),
                 is_solved: false,
-            })
+            };
+
+            if sudoku.is_consistent() {
+                Ok(sudoku)
+            } else {
+                Err(SudokuError::Contradiction)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Sudoku {
+    type Err = SudokuError;
+
+    /// Parses the standard single-line 81-character representation of a
+    /// sudoku, where `1`-`9` are clues and `0` or `.` denote blanks.
+    /// Interior whitespace is ignored, so 9-line grid dumps parse too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut grid = [0u8; 81];
+        let mut len = 0;
+
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            if len == 81 {
+                return Err(SudokuError::InvalidLength);
+            }
+
+            grid[len] = match c {
+                '.' => 0,
+                '0'..='9' => c.to_digit(10).unwrap() as u8,
+                _ => return Err(SudokuError::InvalidCharacter(c)),
+            };
+
+            len += 1;
+        }
+
+        if len != 81 {
+            return Err(SudokuError::InvalidLength);
         }
 
+        Self::try_from(grid)
+    }
+}
+
+impl TryFrom<&str> for Sudoku {
+    type Error = SudokuError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
     }
 }
 
@@ -182,16 +964,19 @@ impl Default for Sudoku {
 
 impl std::fmt::Display for Sudoku {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.grid
-            .chunks_exact(9)
-            .flat_map(|row|
-                row
-                    .iter()
-                    .map(|n| if *n != 0 { char::from_digit(*n as u32, 10).unwrap() } else { '_' })
-                    .intersperse(' ')
-                    .chain(Some('\n'))
-            )
-            .try_for_each(|c| f.write_char(c))
+        for row in self.grid.chunks_exact(9) {
+            for (i, n) in row.iter().enumerate() {
+                if i > 0 {
+                    f.write_char(' ')?;
+                }
+
+                f.write_char(if *n != 0 { char::from_digit(*n as u32, 10).unwrap() } else { '_' })?;
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
     }
 }
 
@@ -199,15 +984,169 @@ impl std::fmt::Display for Sudoku {
 #[derive(Debug)]
 pub enum SudokuError {
     /// The case when there is a number greater than 9.
-    InvalidCell
+    InvalidCell,
+    /// The case when a parsed string doesn't contain exactly 81 non-whitespace characters.
+    InvalidLength,
+    /// The case when a parsed string contains a character that isn't `0`-`9`, `.` or whitespace.
+    InvalidCharacter(char),
+    /// The case when the same nonzero digit appears twice in a row, column or box.
+    Contradiction,
 }
 
 impl std::fmt::Display for SudokuError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
             Self::InvalidCell => writeln!(f, "There are invalid cells (greater than 9) in the given sudoku."),
+            Self::InvalidLength => writeln!(f, "The given sudoku doesn't contain exactly 81 cells."),
+            Self::InvalidCharacter(c) => writeln!(f, "The given sudoku contains an invalid character: '{}'.", c),
+            Self::Contradiction => writeln!(f, "The given sudoku has a repeated digit in a row, column or box."),
         }
     }
 }
 
 impl std::error::Error for SudokuError {}
+
+/// A row, column or box a [`Technique::HiddenSingle`] was found within.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum House {
+    Row(usize),
+    Column(usize),
+    Box(usize),
+}
+
+impl House {
+    /// Returns the 9 cell indices belonging to this house.
+    fn cells(self) -> [usize; 9] {
+        match self {
+            Self::Row(y) => std::array::from_fn(|x| y * 9 + x),
+            Self::Column(x) => std::array::from_fn(|y| y * 9 + x),
+            Self::Box(b) => {
+                let by = (b / 3) * 3;
+                let bx = (b % 3) * 3;
+                std::array::from_fn(|i| (by + i / 3) * 9 + bx + i % 3)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for House {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Row(y) => write!(f, "row {}", y + 1),
+            Self::Column(x) => write!(f, "column {}", x + 1),
+            Self::Box(b) => write!(f, "box {}", b + 1),
+        }
+    }
+}
+
+/// The human solving technique that justified a [`SolvingStep`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Technique {
+    /// The cell had exactly one remaining candidate.
+    NakedSingle,
+    /// The digit fit in only one empty cell of the named house.
+    HiddenSingle(House),
+}
+
+/// A single step of human-style reasoning recorded by [`Sudoku::solve_logged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolvingStep {
+    cell: (usize, usize),
+    digit: u8,
+    technique: Technique,
+}
+
+impl SolvingStep {
+    /// The `(x, y)` coordinates of the cell this step filled in.
+    pub fn cell(&self) -> (usize, usize) {
+        self.cell
+    }
+
+    /// The digit placed by this step.
+    pub fn digit(&self) -> u8 {
+        self.digit
+    }
+
+    /// The technique that justified this step.
+    pub fn technique(&self) -> Technique {
+        self.technique
+    }
+}
+
+impl std::fmt::Display for SolvingStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = Sudoku::cell_name(self.cell);
+
+        match self.technique {
+            Technique::NakedSingle => write!(f, "naked single at {} = {}", name, self.digit),
+            Technique::HiddenSingle(house) => {
+                write!(f, "hidden single at {} = {} in {}", name, self.digit, house)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str =
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+    const SOLUTION: &str =
+        "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+    const UNSOLVABLE: &str =
+        "030000002000100300198002000000701400400800010000024056061507000207009030000006100";
+
+    #[test]
+    fn solve_finds_the_known_solution() {
+        let mut sudoku: Sudoku = PUZZLE.parse().unwrap();
+        sudoku.solve();
+
+        let solution: Sudoku = SOLUTION.parse().unwrap();
+        assert_eq!(sudoku, solution.grid());
+    }
+
+    #[test]
+    fn solve_dlx_agrees_with_solve() {
+        let mut sudoku: Sudoku = PUZZLE.parse().unwrap();
+        sudoku.solve_dlx();
+
+        let solution: Sudoku = SOLUTION.parse().unwrap();
+        assert_eq!(sudoku, solution.grid());
+    }
+
+    #[test]
+    fn unsolvable_grid_is_consistent_but_has_no_solution() {
+        let sudoku: Sudoku = UNSOLVABLE.parse().unwrap();
+
+        assert!(sudoku.is_consistent());
+        assert_eq!(sudoku.count_solutions(2), 0);
+        assert!(!sudoku.is_unique());
+
+        let mut attempt = sudoku;
+        attempt.solve();
+        assert_eq!(attempt, sudoku.grid());
+    }
+
+    #[test]
+    fn count_solutions_and_is_unique_detect_a_non_unique_grid() {
+        let sudoku = Sudoku::default();
+
+        assert_eq!(sudoku.count_solutions(2), 2);
+        assert!(!sudoku.is_unique());
+
+        let solved: Sudoku = SOLUTION.parse().unwrap();
+        assert_eq!(solved.count_solutions(2), 1);
+        assert!(solved.is_unique());
+    }
+
+    #[test]
+    fn solve_logged_reaches_the_known_solution() {
+        let mut sudoku: Sudoku = PUZZLE.parse().unwrap();
+        let steps = sudoku.solve_logged();
+
+        let solution: Sudoku = SOLUTION.parse().unwrap();
+        assert_eq!(sudoku, solution.grid());
+        assert!(!steps.is_empty());
+    }
+}